@@ -0,0 +1,279 @@
+// --- INTERPRETER ---
+// Owns the 'Garage' runtime: executing a parsed program, validating it
+// without running it, and the field-matching logic both depend on.
+
+use crate::parse::MotoError;
+use crate::types::{Assignment, Command, Condition, Expr, Motorcycle, Positioned, Query, UpdateStmt, Value};
+use serde::Serialize;
+
+// The 'Garage' struct holds the state of our program (the list of bikes).
+// It executes the AST (the Vec<Command>).
+#[derive(Debug, Default)]
+pub struct Garage {
+  bikes: Vec<Motorcycle>, // Our in-memory "database"
+}
+
+// The structured result of one GET/COUNT/UPDATE/DELETE command. Kept as
+// data (rather than a pre-formatted String) so callers like the CLI can
+// render it either as plain text or, via `serde`, as JSON.
+// `content = "value"` (adjacent tagging) rather than the default internal
+// tagging, because an internally-tagged newtype variant can only wrap a
+// map-shaped value, and `Names`/`Count`/etc. wrap a sequence/integer.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "type", content = "value", rename_all = "snake_case")]
+pub enum QueryResult {
+  Names(Vec<String>), // Result of a 'GET' query
+  Count(usize), // Result of a 'COUNT' query
+  Updated(usize), // Result of an 'UPDATE' command: bikes changed
+  Deleted(usize), // Result of a 'DELETE' command: bikes removed
+}
+
+impl Garage {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+    // Semantically checks a program without executing it: every WHERE
+    // condition and every 'SET' assignment must reference a known field
+    // ('type'/'year'/'engine') and pair it with an operator/value (or
+    // just a value, for assignments) combination that field supports.
+    // Unlike 'matches'/'apply_assignment', which silently return
+    // 'false'/coerce on a mismatch, this collects *every* violation in
+    // the program so a caller like the 'check' CLI command can report
+    // them all at once.
+  pub fn validate(program: &[Command]) -> Result<(), Vec<MotoError>> {
+    let mut errors = Vec::new();
+    for command in program {
+      match command {
+        Command::Definition(_) => {}
+        Command::Get(query) | Command::Count(query) | Command::Delete(query) => {
+          if let Some(filter) = &query.filter {
+            validate_expr(filter, &mut errors);
+          }
+        }
+        Command::Update(update) => {
+          if let Some(filter) = &update.filter {
+            validate_expr(filter, &mut errors);
+          }
+          validate_assignment(&update.assignment, &mut errors);
+        }
+      }
+    }
+    if errors.is_empty() {
+      Ok(())
+    } else {
+      Err(errors)
+    }
+  }
+
+    // The main execution loop. It takes the AST and runs each command.
+  pub fn execute(&mut self, program: Vec<Command>) -> Result<Vec<QueryResult>, MotoError> {
+    let mut results = Vec::new(); // Collects output from GET/COUNT/UPDATE/DELETE
+
+    for command in program {
+      match command {
+                // If the command is 'Definition', add the bike to our state.
+        Command::Definition(bike) => {
+          self.bikes.push(bike.node);
+        }
+                // If 'Get', run the query and add the list of names to results.
+        Command::Get(query) => {
+          results.push(QueryResult::Names(self.run_query_get(query)));
+        }
+                // If 'Count', run the query and add the count to results.
+        Command::Count(query) => {
+          let count = self.filter_bikes(&query).count();
+          results.push(QueryResult::Count(count));
+        }
+                // If 'Update', apply the assignment to every matching bike
+                // and report how many were changed.
+        Command::Update(update) => {
+          let changed = self.apply_update(update);
+          results.push(QueryResult::Updated(changed));
+        }
+                // If 'Delete', remove every matching bike and report how
+                // many were removed.
+        Command::Delete(query) => {
+          let removed = self.apply_delete(query);
+          results.push(QueryResult::Deleted(removed));
+        }
+      }
+    }
+    Ok(results) // Return all collected results.
+  }
+
+    // A reusable helper function to filter bikes based on a query.
+    // It returns an iterator for efficiency (no new Vec is created here).
+  fn filter_bikes<'a>(&'a self, query: &'a Query) -> impl Iterator<Item = &'a Motorcycle> {
+    self.bikes
+      .iter()
+            // .is_none_or(...) means: if the condition is 'None', return 'true' (match all bikes).
+            // Otherwise, call the 'bike.eval(expr)' function.
+      .filter(move |bike| query.filter.as_ref().is_none_or(|expr| bike.eval(expr)))
+  }
+
+    // Applies `update`'s assignment to every bike matching its filter (or
+    // all bikes, if the filter is 'None'), returning how many were changed.
+  fn apply_update(&mut self, update: UpdateStmt) -> usize {
+    let mut changed = 0;
+    for bike in &mut self.bikes {
+      if update.filter.as_ref().is_none_or(|expr| bike.eval(expr)) {
+        apply_assignment(bike, &update.assignment.node);
+        changed += 1;
+      }
+    }
+    changed
+  }
+
+    // Removes every bike matching `query`'s filter (or all bikes, if the
+    // filter is 'None'), returning how many were removed.
+  fn apply_delete(&mut self, query: Query) -> usize {
+    let before = self.bikes.len();
+    self
+      .bikes
+      .retain(|bike| !query.filter.as_ref().is_none_or(|expr| bike.eval(expr)));
+    before - self.bikes.len()
+  }
+
+    // The logic for 'GET'. It uses the filter helper and then collects the names.
+  fn run_query_get(&self, query: Query) -> Vec<String> {
+    self.filter_bikes(&query)
+      .map(|bike| bike.name.clone()) // Get only the names
+      .collect() // Collect into a new Vec<String>
+  }
+}
+
+// Logic for checking if a single bike matches a 'WHERE' expression.
+impl Motorcycle {
+    // Recursively evaluates a boolean 'WHERE' expression against this
+    // bike, short-circuiting 'And'/'Or' the same way Rust's '&&'/'||' do.
+  fn eval(&self, expr: &Expr) -> bool {
+    match expr {
+      Expr::Compare(condition) => self.matches(&condition.node),
+      Expr::And(lhs, rhs) => self.eval(lhs) && self.eval(rhs),
+      Expr::Or(lhs, rhs) => self.eval(lhs) || self.eval(rhs),
+      Expr::Not(inner) => !self.eval(inner),
+    }
+  }
+
+  fn matches(&self, condition: &Condition) -> bool {
+    match condition.field.as_str() { // Check which field we are filtering on
+      "type" => self
+        .bike_type
+        .as_ref() // Get an Option<&String>
+                    // Check if the bike's type matches the value's string.
+        .is_some_and(|t| *t == condition.value.node.value_as_string()),
+      "year" => self.year.is_some_and(|y| { // 'y' is the bike's year
+        compare(y, &condition.operator, condition.value.node.value_as_number())
+      }),
+      "engine" => self.engine_cc.is_some_and(|e| { // 'e' is the bike's engine
+        compare(e, &condition.operator, condition.value.node.value_as_number())
+      }),
+      _ => false, // Unknown field, so it's not a match.
+    }
+  }
+}
+// A simple comparison helper for numbers.
+fn compare(a: u32, op: &str, b: u32) -> bool {
+  match op {
+    "=" => a == b,
+    ">" => a > b,
+    "<" => a < b,
+    _ => false, // Invalid operator
+  }
+}
+
+// Applies a single "field = value" assignment to a bike, the same way
+// `parse_definition` fills in a bike's fields from its properties.
+fn apply_assignment(bike: &mut Motorcycle, assignment: &Assignment) {
+  match assignment.field.as_str() {
+    "year" => bike.year = Some(assignment.value.node.value_as_number()),
+    "engine" => bike.engine_cc = Some(assignment.value.node.value_as_number()),
+    "type" => bike.bike_type = Some(assignment.value.node.value_as_string()),
+    _ => {} // Unknown field, ignored
+  }
+}
+
+// Walks a boolean 'WHERE' expression, validating every comparison it
+// contains. AND/OR/NOT nodes carry no field/operator/value of their own,
+// so they just recurse into their operands.
+fn validate_expr(expr: &Expr, errors: &mut Vec<MotoError>) {
+  match expr {
+    Expr::Compare(condition) => validate_condition(condition, errors),
+    Expr::And(lhs, rhs) | Expr::Or(lhs, rhs) => {
+      validate_expr(lhs, errors);
+      validate_expr(rhs, errors);
+    }
+    Expr::Not(inner) => validate_expr(inner, errors),
+  }
+}
+
+// Checks a single 'SET field = value' assignment the same way
+// 'validate_condition' checks a WHERE condition: an unknown field or a
+// value of the wrong type is reported instead of silently becoming a
+// no-op or '0' via 'apply_assignment'/'value_as_number'. Unknown-field
+// errors point at the assignment itself (anchored at the field, like
+// 'validate_condition' does); wrong-value-type errors point at the
+// value, since that's the token that's actually wrong.
+fn validate_assignment(assignment: &Positioned<Assignment>, errors: &mut Vec<MotoError>) {
+  let field_pos = assignment.pos;
+  let field = assignment.node.field.as_str();
+  let value_pos = assignment.node.value.pos;
+  let value = &assignment.node.value.node;
+
+  match field {
+    "year" | "engine" if matches!(value, Value::Number(_)) => {}
+    "year" | "engine" => errors.push(MotoError::InterpreterError {
+      message: format!("`{}` expects a number, not {:?}", field, value),
+      pos: value_pos,
+    }),
+    "type" if matches!(value, Value::StringType(_) | Value::StringLiteral(_)) => {}
+    "type" => errors.push(MotoError::InterpreterError {
+      message: "`type` expects a string value".to_string(),
+      pos: value_pos,
+    }),
+    other => errors.push(MotoError::InterpreterError {
+      message: format!("unknown field `{}`", other),
+      pos: field_pos,
+    }),
+  }
+}
+
+// Checks a single WHERE condition against the field/operator/value rules
+// described on 'Garage::validate', pushing a positioned error for each
+// violation instead of returning on the first one.
+fn validate_condition(condition: &Positioned<Condition>, errors: &mut Vec<MotoError>) {
+  let pos = condition.pos;
+  let field = condition.node.field.as_str();
+  let operator = condition.node.operator.as_str();
+  let value = &condition.node.value.node;
+
+  match field {
+    "year" | "engine" => match operator {
+      "=" | ">" | "<" if matches!(value, Value::Number(_)) => {}
+      "=" | ">" | "<" => errors.push(MotoError::InterpreterError {
+        message: format!("`{}` expects a number, not {:?}", field, value),
+        pos,
+      }),
+      other => errors.push(MotoError::InterpreterError {
+        message: format!("unknown operator `{}`", other),
+        pos,
+      }),
+    },
+    "type" => match operator {
+      "=" if matches!(value, Value::StringType(_) | Value::StringLiteral(_)) => {}
+      "=" => errors.push(MotoError::InterpreterError {
+        message: "`type` expects a string value".to_string(),
+        pos,
+      }),
+      other => errors.push(MotoError::InterpreterError {
+        message: format!("`type` only supports `=`, not `{}`", other),
+        pos,
+      }),
+    },
+    other => errors.push(MotoError::InterpreterError {
+      message: format!("unknown field `{}`", other),
+      pos,
+    }),
+  }
+}
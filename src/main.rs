@@ -1,8 +1,8 @@
 
-use clap::{Parser, Subcommand};
-use motogarage_parser::{Garage, parse_moto_file};
-use std::fs; 
-use std::path::PathBuf; 
+use clap::{Parser, Subcommand, ValueEnum};
+use motogarage_parser::{Garage, QueryResult, parse_moto_file};
+use std::fs;
+use std::path::PathBuf;
 
 // --- 1. COMMAND-LINE INTERFACE  DEFINITION ---
 // 'clap' uses this struct to generate the --help menu and parse args.
@@ -10,12 +10,24 @@ use std::path::PathBuf;
 #[command(
   name = "moto",
   version = "1.0",
-  about = "A parser and interpreter for MotoGarage DSL" 
+  about = "A parser and interpreter for MotoGarage DSL"
 )]
 struct Cli {
     // This holds whichever subcommand the user chose (e.g., 'parse' or 'credits').
   #[command(subcommand)]
   command: Commands,
+
+    /// Output format for query results
+  #[arg(long, global = true, value_enum, default_value_t = OutputFormat::Text)]
+  format: OutputFormat,
+}
+
+// The two ways 'parse' can render its query results.
+#[derive(ValueEnum, Clone, Copy, Debug, Default)]
+enum OutputFormat {
+  #[default]
+  Text,
+  Json,
 }
 
 // Defines the available subcommands.
@@ -26,6 +38,12 @@ enum Commands {
         /// The path to the .moto file
     #[arg(required = true)]
     file_path: PathBuf,
+  },
+    /// Validates a .moto file without executing it
+  Check {
+        /// The path to the .moto file
+    #[arg(required = true)]
+    file_path: PathBuf,
   },
     /// Displays author information
   Credits,
@@ -37,6 +55,7 @@ enum Commands {
 fn main() -> anyhow::Result<()> {
     // 'clap' parses arguments from the command line.
   let cli = Cli::parse();
+  let format = cli.format;
 
     // Figure out which command the user ran.
   match cli.command {
@@ -52,25 +71,63 @@ fn main() -> anyhow::Result<()> {
         .map_err(|e| anyhow::anyhow!("Cannot read file {:?}: {}", file_path, e))?;
             
             // 2. Call our library to parse the file content into an AST.
-            // The '?' operator will automatically convert our library's 'MotoError'
-            // into an 'anyhow::Error' and return it if something fails.
-      let ast = parse_moto_file(&content)?;
+            // On failure, print the offending line with a '^' underneath
+            // instead of just the raw error.
+      let ast = match parse_moto_file(&content) {
+        Ok(ast) => ast,
+        Err(err) => {
+          eprintln!("{}", err.render(&content));
+          anyhow::bail!("failed to parse {:?}", file_path);
+        }
+      };
       eprintln!("[INFO] File parsed.");
 
       eprintln!("[INFO] Procesing queries...");
             // 3. Create the interpreter and execute the AST.
       let mut garage = Garage::new();
-      let results = garage.execute(ast)?; // '?' also handles interpreter errors.
-
-            // 4. Print the results.
-      if results.is_empty() {
-        eprintln!("No result from queries."); // Log message to stderr
-      } else {
-                // Use 'println!' for the actual, successful output.
-                // This goes to 'stdout', so it can be piped to other programs.
-        println!("--- Result ---");
-        for result in results {
-          println!("- {}", result);
+      let results = match garage.execute(ast) {
+        Ok(results) => results,
+        Err(err) => {
+          eprintln!("{}", err.render(&content));
+          anyhow::bail!("failed to execute {:?}", file_path);
+        }
+      };
+
+            // 4. Print the results, in whichever format was requested.
+            // This goes to 'stdout', so it can be piped to other programs.
+      match format {
+        OutputFormat::Text => print_results_text(&results),
+        OutputFormat::Json => {
+          println!("{}", serde_json::to_string_pretty(&results)?);
+        }
+      }
+    }
+
+        // --- 'check' COMMAND LOGIC ---
+    Commands::Check { file_path } => {
+      eprintln!("[INFO] Reading file: {:?}", file_path);
+
+      let content = fs::read_to_string(&file_path)
+        .map_err(|e| anyhow::anyhow!("Cannot read file {:?}: {}", file_path, e))?;
+
+            // 1. Parse only. 'check' never executes the program.
+      let ast = match parse_moto_file(&content) {
+        Ok(ast) => ast,
+        Err(err) => {
+          eprintln!("{}", err.render(&content));
+          anyhow::bail!("failed to parse {:?}", file_path);
+        }
+      };
+
+            // 2. Run the semantic validator, which collects every error
+            // instead of stopping at the first one.
+      match Garage::validate(&ast) {
+        Ok(()) => println!("{:?}: OK", file_path),
+        Err(errors) => {
+          for err in &errors {
+            eprintln!("{}", err.render(&content));
+          }
+          anyhow::bail!("{:?}: {} error(s) found", file_path, errors.len());
         }
       }
     }
@@ -84,4 +141,28 @@ fn main() -> anyhow::Result<()> {
   }
 
   Ok(()) // Everything finished successfully.
+}
+
+// Renders query results the way 'parse' always has: a '- name' line per
+// GET match, a 'Bikes found: N' line per COUNT, and their own line per
+// UPDATE/DELETE so they can't be mistaken for a COUNT result.
+fn print_results_text(results: &[QueryResult]) {
+  if results.is_empty() {
+    eprintln!("No result from queries."); // Log message to stderr
+    return;
+  }
+
+  println!("--- Result ---");
+  for result in results {
+    match result {
+      QueryResult::Names(names) => {
+        for name in names {
+          println!("- {}", name);
+        }
+      }
+      QueryResult::Count(count) => println!("- Bikes found: {}", count),
+      QueryResult::Updated(count) => println!("- Bikes updated: {}", count),
+      QueryResult::Deleted(count) => println!("- Bikes deleted: {}", count),
+    }
+  }
 }
\ No newline at end of file
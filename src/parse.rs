@@ -0,0 +1,307 @@
+// --- PARSING LOGIC (Text -> AST) ---
+// Owns the pest grammar link, the position-tracking error type, and the
+// functions that transform pest's `Pairs` into the `types` AST.
+
+use crate::types::{
+  Assignment, Command, Condition, Expr, Motorcycle, Pos, Positioned, Query, UpdateStmt, Value,
+};
+use pest::Parser;
+use pest::pratt_parser::{Assoc, Op, PrattParser};
+use pest_derive::Parser;
+use std::sync::OnceLock;
+use thiserror::Error;
+
+// --- PARSER SETUP ---
+// This is the main link to pest.
+// It tells pest_derive to generate a parser struct named MotogarageParser...
+#[derive(Parser)]
+#[grammar = "src/grammar.pest"] // ...and to use this grammar file to build it.
+pub struct MotogarageParser;
+
+// --- ERROR HANDLING ---
+// Defines our library's custom error types.
+// 'thiserror' makes it easy to create good error messages.
+#[derive(Error, Debug)]
+pub enum MotoError {
+    // This variant will automatically wrap any parsing errors from pest.
+  #[error("Parsing error: {0}")]
+  ParseError(#[from] pest::error::Error<Rule>),
+
+    // A custom error for our own logic (e.g., if interpretation fails).
+    // Carries the source position of the node that caused it, so the CLI
+    // can point at the offending token instead of just printing a message.
+  #[error("Interp error: {message}")]
+  InterpreterError { message: String, pos: Pos },
+}
+
+impl MotoError {
+    // Renders the error as a human-readable message, with a caret ('^')
+    // underneath the offending token for errors that carry a `Pos`.
+    // `source` must be the same text that was originally parsed.
+  pub fn render(&self, source: &str) -> String {
+    match self {
+            // pest's own `Display` impl already draws a caret under the
+            // offending token, so there's nothing extra to do here.
+      MotoError::ParseError(err) => err.to_string(),
+      MotoError::InterpreterError { message, pos } => render_caret(source, pos, message),
+    }
+  }
+}
+
+// Shared by `MotoError::render`: prints `message`, the offending source
+// line, and a caret under the column the error points at.
+fn render_caret(source: &str, pos: &Pos, message: &str) -> String {
+  let line = source.lines().nth(pos.line.saturating_sub(1)).unwrap_or("");
+  let caret = format!("{}^", " ".repeat(pos.column.saturating_sub(1)));
+  format!("Interp error: {}\n{}\n{}", message, line, caret)
+}
+
+// Converts byte offsets (as reported by `pair.as_span().start()`) into
+// line/column positions. pest visits pairs in increasing start-offset
+// order during a single parse, so this only ever needs to walk forward
+// from the last offset it was asked about, which keeps the whole file
+// a single O(n) pass instead of O(n) per lookup.
+struct PositionCalculator<'a> {
+  input: &'a str,
+  cursor: usize, // byte offset already accounted for
+  line: usize,
+  column: usize, // counted in Unicode scalar values, not bytes
+}
+
+impl<'a> PositionCalculator<'a> {
+  fn new(input: &'a str) -> Self {
+    Self {
+      input,
+      cursor: 0,
+      line: 1,
+      column: 1,
+    }
+  }
+
+    // Returns the `Pos` for `offset`, advancing the cursor up to it.
+    // `offset` must be >= every offset passed to this call so far.
+  fn pos_at(&mut self, offset: usize) -> Pos {
+    for ch in self.input[self.cursor..offset].chars() {
+      if ch == '\n' {
+        self.line += 1;
+        self.column = 1;
+      } else {
+        self.column += 1;
+      }
+    }
+    self.cursor = offset;
+    Pos {
+      line: self.line,
+      column: self.column,
+    }
+  }
+}
+
+// The main entry point for parsing.
+pub fn parse_moto_file(input: &str) -> Result<Vec<Command>, MotoError> {
+    // 1. Call pest to parse the input string using the 'file' rule.
+  let pairs = MotogarageParser::parse(Rule::file, input)?; // '?' handles errors
+  let mut ast = Vec::new();
+    // Tracks our position as we walk the pairs, so every node we build can
+    // be tagged with where it started in `input`.
+  let mut calc = PositionCalculator::new(input);
+
+    // 2. Iterate over the pairs inside the 'file' rule.
+    // We use .next().unwrap().into_inner() to step inside the 'file' pair.
+  for pair in pairs.into_iter().next().unwrap().into_inner() {
+    match pair.as_rule() {
+      Rule::command => ast.push(parse_command(pair, &mut calc)), // Found a command, parse it.
+      Rule::EOI => (), // End Of Input, we are done.
+      _ => unreachable!(), // Should not happen if grammar is correct.
+    }
+  }
+  Ok(ast) // Return the completed Abstract Syntax Tree (AST).
+}
+
+// This function routes a 'command' pair to the correct specific parser.
+fn parse_command(pair: pest::iterators::Pair<Rule>, calc: &mut PositionCalculator) -> Command {
+    // A 'command' pair contains one of the command variants below.
+  let inner = pair.into_inner().next().unwrap();
+  match inner.as_rule() {
+    Rule::definition => Command::Definition(parse_definition(inner, calc)),
+    Rule::query_get => Command::Get(parse_query(inner, calc)),
+    Rule::query_count => Command::Count(parse_query(inner, calc)),
+    Rule::update_stmt => Command::Update(parse_update_stmt(inner, calc)),
+        // 'delete_stmt' has the same shape as 'query_get'/'query_count'
+        // ("KEYWORD BIKES where_clause?"), so it reuses 'parse_query'.
+    Rule::delete_stmt => Command::Delete(parse_query(inner, calc)),
+    _ => unreachable!(),
+  }
+}
+
+// Parses a 'definition' pair into a 'Motorcycle' struct, tagged with the
+// position the 'DEFINE' command started at.
+fn parse_definition(
+  pair: pest::iterators::Pair<Rule>,
+  calc: &mut PositionCalculator,
+) -> Positioned<Motorcycle> {
+  let pos = calc.pos_at(pair.as_span().start());
+  let mut inner_pairs = pair.into_inner();
+    // The first inner pair is always the 'string_literal' (the name).
+  let name = parse_string_literal(inner_pairs.next().unwrap());
+
+  let mut bike = Motorcycle {
+    name,
+    ..Default::default() // Fill the rest with None/Default
+  };
+
+    // The second inner pair is 'properties'. We loop over them.
+  let properties_pairs = inner_pairs.next().unwrap().into_inner();
+  for prop_pair in properties_pairs { // Each 'prop_pair' is a 'property' rule
+    let mut prop_inner = prop_pair.into_inner();
+    let field_name = prop_inner.next().unwrap().as_str(); // e.g., "year"
+    let value_pair = prop_inner.next().unwrap(); // The 'value' pair
+
+        // Match on the field name and update the bike struct.
+    match field_name {
+      "year" => bike.year = Some(parse_value(value_pair, calc).node.value_as_number()),
+      "engine" => bike.engine_cc = Some(parse_value(value_pair, calc).node.value_as_number()),
+      "type" => bike.bike_type = Some(parse_value(value_pair, calc).node.value_as_string()),
+      _ => {} // Ignore unknown properties
+    }
+  }
+  Positioned { pos, node: bike }
+}
+
+// Parses a 'query_get', 'query_count' or 'delete_stmt' pair into a 'Query' struct.
+// Note: This logic is shared by GET, COUNT and DELETE.
+fn parse_query(pair: pest::iterators::Pair<Rule>, calc: &mut PositionCalculator) -> Query {
+    // A query pair contains an optional 'where_clause'.
+  let where_clause_pair = pair.into_inner().next();
+
+    // If the 'where_clause' exists, get the 'expr' from inside it.
+  let expr_pair = where_clause_pair.map(|where_pair| where_pair.into_inner().next().unwrap());
+
+    // If the 'expr' exists, parse it.
+  let filter = expr_pair.map(|p| parse_expr(p, calc));
+
+  Query { filter } // Create the Query struct
+}
+
+// Parses an 'update_stmt' pair into an 'UpdateStmt' struct.
+fn parse_update_stmt(
+  pair: pest::iterators::Pair<Rule>,
+  calc: &mut PositionCalculator,
+) -> UpdateStmt {
+    // An 'update_stmt' pair contains an 'assignment' and an optional 'where_clause'.
+  let mut inner = pair.into_inner();
+  let assignment = parse_assignment(inner.next().unwrap(), calc);
+
+  let expr_pair = inner.next().map(|where_pair| where_pair.into_inner().next().unwrap());
+  let filter = expr_pair.map(|p| parse_expr(p, calc));
+
+  UpdateStmt { assignment, filter }
+}
+
+// Parses an 'assignment' pair into an 'Assignment' struct, tagged with
+// the position the assignment started at (anchored at its field, like
+// 'parse_condition' does for a WHERE condition).
+fn parse_assignment(
+  pair: pest::iterators::Pair<Rule>,
+  calc: &mut PositionCalculator,
+) -> Positioned<Assignment> {
+  let pos = calc.pos_at(pair.as_span().start());
+    // An 'assignment' pair contains 'ident', 'value'.
+  let mut inner = pair.into_inner();
+  let field = inner.next().unwrap().as_str().to_string();
+  let value = parse_value(inner.next().unwrap(), calc);
+  Positioned {
+    pos,
+    node: Assignment { field, value },
+  }
+}
+
+// Builds (once) the operator-precedence table used to turn the flat
+// sequence of pairs inside an 'expr' rule into a properly nested `Expr`
+// tree: OR binds loosest, then AND, then the NOT prefix.
+fn expr_parser() -> &'static PrattParser<Rule> {
+  static PARSER: OnceLock<PrattParser<Rule>> = OnceLock::new();
+  PARSER.get_or_init(|| {
+    PrattParser::new()
+      .op(Op::infix(Rule::or, Assoc::Left))
+      .op(Op::infix(Rule::and, Assoc::Left))
+      .op(Op::prefix(Rule::not))
+  })
+}
+
+// Parses an 'expr' pair into an `Expr` tree, using the Pratt parser above
+// to resolve AND/OR/NOT precedence.
+fn parse_expr(pair: pest::iterators::Pair<Rule>, calc: &mut PositionCalculator) -> Expr {
+  expr_parser()
+    .map_primary(|primary| parse_primary(primary, calc))
+    .map_prefix(|op, rhs| match op.as_rule() {
+      Rule::not => Expr::Not(Box::new(rhs)),
+      _ => unreachable!(),
+    })
+    .map_infix(|lhs, op, rhs| match op.as_rule() {
+      Rule::and => Expr::And(Box::new(lhs), Box::new(rhs)),
+      Rule::or => Expr::Or(Box::new(lhs), Box::new(rhs)),
+      _ => unreachable!(),
+    })
+    .parse(pair.into_inner())
+}
+
+// Parses a 'primary' pair, which is either a bare 'condition' or a
+// parenthesized 'expr'.
+fn parse_primary(pair: pest::iterators::Pair<Rule>, calc: &mut PositionCalculator) -> Expr {
+  let inner = pair.into_inner().next().unwrap();
+  match inner.as_rule() {
+    Rule::condition => Expr::Compare(parse_condition(inner, calc)),
+    Rule::expr => parse_expr(inner, calc),
+    _ => unreachable!(),
+  }
+}
+
+// Parses a 'condition' pair into a 'Condition' struct, tagged with the
+// position the condition started at.
+fn parse_condition(
+  pair: pest::iterators::Pair<Rule>,
+  calc: &mut PositionCalculator,
+) -> Positioned<Condition> {
+  let pos = calc.pos_at(pair.as_span().start());
+    // A 'condition' pair contains 'ident', 'operator', 'value'.
+  let mut inner = pair.into_inner();
+  let field = inner.next().unwrap().as_str().to_string();
+  let operator = inner.next().unwrap().as_str().to_string();
+  let value = parse_value(inner.next().unwrap(), calc);
+  Positioned {
+    pos,
+    node: Condition {
+      field,
+      operator,
+      value,
+    },
+  }
+}
+
+// Parses a 'value' pair into our 'Value' enum, tagged with the position
+// it started at.
+fn parse_value(
+  pair: pest::iterators::Pair<Rule>,
+  calc: &mut PositionCalculator,
+) -> Positioned<Value> {
+  let pos = calc.pos_at(pair.as_span().start());
+    // A 'value' pair contains one of its inner rules.
+  let inner = pair.into_inner().next().unwrap();
+  let node = match inner.as_rule() {
+    Rule::number => Value::Number(inner.as_str().parse().unwrap_or(0)),
+    Rule::number_with_unit => {
+            // We must strip "cc" before parsing to a number.
+      Value::Number(inner.as_str().replace("cc", "").parse().unwrap_or(0))
+    }
+    Rule::ident => Value::StringType(inner.as_str().to_string()),
+    Rule::string_literal => Value::StringLiteral(parse_string_literal(inner)),
+    _ => unreachable!(),
+  };
+  Positioned { pos, node }
+}
+
+// Helper to clean up quoted strings.
+fn parse_string_literal(pair: pest::iterators::Pair<Rule>) -> String {
+  pair.as_str().trim_matches('"').to_string() // Removes the "" from the string.
+}
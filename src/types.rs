@@ -0,0 +1,113 @@
+// --- ABSTRACT SYNTAX TREE (AST) ---
+// These structs and enums represent our language's *structure*, independent
+// of how it's parsed (see `parse`) or executed (see `interp`). Depending on
+// just this module lets tooling (formatters, linters) work with the AST
+// without pulling in the interpreter.
+
+use serde::Serialize;
+
+// A line/column pair, 1-indexed like most editors, pointing at a byte
+// offset in the original source text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Pos {
+  pub line: usize,
+  pub column: usize,
+}
+
+// Wraps an AST node together with the position it started at in the
+// source text, the way async-graphql's `Positioned<T>` tags every node it
+// resolves so errors can point back at the query that produced them.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Positioned<T> {
+  pub pos: Pos,
+  pub node: T,
+}
+
+// 'Command' is the top-level instruction. A file is a Vec<Command>.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub enum Command {
+  Definition(Positioned<Motorcycle>), // Represents a 'DEFINE' command
+  Get(Query), // Represents a 'GET' command
+  Count(Query), // Represents a 'COUNT' command
+  Update(UpdateStmt), // Represents an 'UPDATE ... SET ...' command
+  Delete(Query), // Represents a 'DELETE' command
+}
+
+// Represents the data for a single motorcycle.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct Motorcycle {
+  pub name: String,
+  pub year: Option<u32>, // 'Option' is used because fields are optional
+  pub engine_cc: Option<u32>,
+  pub bike_type: Option<String>,
+}
+
+// Represents a 'GET', 'COUNT' or 'DELETE' query.
+// It holds an optional boolean filter expression. If 'None', it means
+// "all bikes".
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Query {
+  pub filter: Option<Expr>,
+}
+
+// Represents an 'UPDATE BIKES SET ... WHERE ...' command: an assignment
+// applied to every bike matching an optional filter (like `Query.filter`,
+// 'None' means "all bikes").
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct UpdateStmt {
+  pub assignment: Positioned<Assignment>,
+  pub filter: Option<Expr>,
+}
+
+// A single "field = value" assignment, like "year = 2022".
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Assignment {
+  pub field: String, // "year"
+  pub value: Positioned<Value>, // Number(2022)
+}
+
+// A boolean 'WHERE' expression, e.g. "year > 2015 AND type = sport".
+// Built from `Condition`s combined with AND/OR/NOT and parentheses.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub enum Expr {
+  Compare(Positioned<Condition>),
+  And(Box<Expr>, Box<Expr>),
+  Or(Box<Expr>, Box<Expr>),
+  Not(Box<Expr>),
+}
+
+// Represents a single comparison, like "year > 2020".
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Condition {
+  pub field: String, // "year"
+  pub operator: String, // ">"
+  pub value: Positioned<Value>, // Number(2020), tagged with where it came from
+}
+impl Condition {} // This is empty, which is fine.
+
+// Represents the different types of values our language supports.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub enum Value {
+  Number(u32), // e.g., 2020, 600
+  StringType(String), // e.g., sport, cruiser (unquoted identifiers)
+  StringLiteral(String), // e.g., "Honda CBR" (quoted strings)
+}
+
+// Helper methods to easily extract Rust values from our AST 'Value' enum.
+impl Value {
+  // Returns the number, or 0 if it's not a number.
+  pub fn value_as_number(&self) -> u32 {
+    match self {
+      Value::Number(n) => *n,
+      _ => 0,
+    }
+  }
+  // Returns the string value, or an empty string.
+  pub fn value_as_string(&self) -> String {
+    match self {
+      Value::StringType(s) => s.clone(),
+      Value::StringLiteral(s) => s.clone(),
+      _ => String::new(),
+    }
+  }
+}
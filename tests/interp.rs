@@ -0,0 +1,127 @@
+use anyhow::Result;
+use motogarage_parser::{Garage, MotoError, Pos, QueryResult, parse_moto_file};
+
+// Test for: Garage::validate rejecting a WHERE clause on an unknown field
+#[test]
+fn test_validate_rejects_unknown_field() -> Result<()> {
+    let ast = parse_moto_file("GET BIKES WHERE bogus = sport")?;
+    let errors = Garage::validate(&ast).unwrap_err();
+    assert_eq!(errors.len(), 1);
+    assert!(matches!(&errors[0], MotoError::InterpreterError { message, .. }
+        if message.contains("unknown field")));
+    Ok(())
+}
+
+// Test for: Garage::validate rejecting a WHERE comparison of the wrong type
+#[test]
+fn test_validate_rejects_type_mismatch() -> Result<()> {
+    let ast = parse_moto_file("GET BIKES WHERE year = sport")?;
+    let errors = Garage::validate(&ast).unwrap_err();
+    assert_eq!(errors.len(), 1);
+    assert!(matches!(&errors[0], MotoError::InterpreterError { message, .. }
+        if message.contains("expects a number")));
+    Ok(())
+}
+
+// Test for: Garage::validate collecting every violation, not just the first
+#[test]
+fn test_validate_collects_every_violation() -> Result<()> {
+    let ast = parse_moto_file("GET BIKES WHERE bogus = sport AND year = sport")?;
+    let errors = Garage::validate(&ast).unwrap_err();
+    assert_eq!(errors.len(), 2);
+    Ok(())
+}
+
+// Test for: QueryResult's JSON encoding. Every variant must actually
+// serialize (the old internal tagging crashed on `Names`/`Count`/etc.
+// because none of them wrap a map), not just the empty-results case.
+#[test]
+fn test_query_result_json_serialization() {
+    let names = QueryResult::Names(vec!["Honda CBR600RR".to_string()]);
+    assert_eq!(
+        serde_json::to_string(&names).unwrap(),
+        r#"{"type":"names","value":["Honda CBR600RR"]}"#
+    );
+
+    let count = QueryResult::Count(3);
+    assert_eq!(
+        serde_json::to_string(&count).unwrap(),
+        r#"{"type":"count","value":3}"#
+    );
+
+    let updated = QueryResult::Updated(2);
+    assert_eq!(
+        serde_json::to_string(&updated).unwrap(),
+        r#"{"type":"updated","value":2}"#
+    );
+
+    let deleted = QueryResult::Deleted(1);
+    assert_eq!(
+        serde_json::to_string(&deleted).unwrap(),
+        r#"{"type":"deleted","value":1}"#
+    );
+}
+
+// Test for: Garage::validate checking a SET assignment the same way it
+// checks a WHERE clause, instead of only validating UPDATE's filter.
+// Also asserts on the reported `pos`: a bad value should point at the
+// value, and an unknown field should point at the assignment's field,
+// not (as a regression would) always point at the value.
+#[test]
+fn test_validate_rejects_bad_assignment() -> Result<()> {
+    let ast = parse_moto_file("UPDATE BIKES SET year = sport WHERE type = cruiser")?;
+    let errors = Garage::validate(&ast).unwrap_err();
+    assert_eq!(errors.len(), 1);
+    assert!(matches!(&errors[0], MotoError::InterpreterError { message, .. }
+        if message.contains("expects a number")));
+    assert!(matches!(&errors[0], MotoError::InterpreterError { pos, .. }
+        if *pos == Pos { line: 1, column: 25 })); // points at `sport`
+
+    let ast = parse_moto_file("UPDATE BIKES SET bogus = 5 WHERE type = cruiser")?;
+    let errors = Garage::validate(&ast).unwrap_err();
+    assert_eq!(errors.len(), 1);
+    assert!(matches!(&errors[0], MotoError::InterpreterError { message, .. }
+        if message.contains("unknown field")));
+    assert!(matches!(&errors[0], MotoError::InterpreterError { pos, .. }
+        if *pos == Pos { line: 1, column: 18 })); // points at `bogus`
+    Ok(())
+}
+
+// Test for: Garage::execute running UPDATE, which should mutate every
+// matching bike and report how many changed (distinct from COUNT)
+#[test]
+fn test_execute_update_mutates_matching_bikes() -> Result<()> {
+    let input = r#"
+        DEFINE bike "Honda CBR600RR" { year: 2015, type: sport }
+        DEFINE bike "Yamaha MT-07" { year: 2015, type: naked }
+        UPDATE BIKES SET year = 2022 WHERE type = sport
+        GET BIKES WHERE year = 2022
+    "#;
+    let ast = parse_moto_file(input)?;
+    let mut garage = Garage::new();
+    let results = garage.execute(ast)?;
+    assert_eq!(results[0], QueryResult::Updated(1));
+    assert_eq!(
+        results[1],
+        QueryResult::Names(vec!["Honda CBR600RR".to_string()])
+    );
+    Ok(())
+}
+
+// Test for: Garage::execute running DELETE, which should remove every
+// matching bike and report how many were removed (distinct from COUNT)
+#[test]
+fn test_execute_delete_removes_matching_bikes() -> Result<()> {
+    let input = r#"
+        DEFINE bike "Honda CBR600RR" { year: 1998 }
+        DEFINE bike "Yamaha MT-07" { year: 2020 }
+        DELETE BIKES WHERE year < 2000
+        COUNT BIKES
+    "#;
+    let ast = parse_moto_file(input)?;
+    let mut garage = Garage::new();
+    let results = garage.execute(ast)?;
+    assert_eq!(results[0], QueryResult::Deleted(1));
+    assert_eq!(results[1], QueryResult::Count(1));
+    Ok(())
+}
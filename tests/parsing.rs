@@ -2,7 +2,7 @@ use anyhow::Result;
 use assert_matches::assert_matches;
 use motogarage_parser::Command;
 use motogarage_parser::{
-    Condition, MotogarageParser, Motorcycle, Query, Rule, Value, parse_moto_file,
+    Condition, Expr, MotogarageParser, Motorcycle, Pos, Positioned, Rule, Value, parse_moto_file,
 };
 use pest::Parser;
 
@@ -60,7 +60,7 @@ fn test_parse_definition() -> Result<()> {
     let ast = parse_moto_file(input)?;
     assert_eq!(ast.len(), 1);
     assert_matches!(&ast[0], Command::Definition(bike) if
-        bike == &Motorcycle {
+        bike.node == Motorcycle {
             name: "Honda CBR600RR".to_string(),
             year: Some(2021),
             engine_cc: Some(599),
@@ -77,13 +77,17 @@ fn test_parse_query_get() -> Result<()> {
     let ast = parse_moto_file(input)?;
     assert_eq!(ast.len(), 1);
     assert_matches!(&ast[0], Command::Get(query) if
-        query == &Query {
-            condition: Some(Condition {
+        query.filter == Some(Expr::Compare(Positioned {
+            pos: Pos { line: 1, column: 17 },
+            node: Condition {
                 field: "type".to_string(),
                 operator: "=".to_string(),
-                value: Value::StringType("sport".to_string()),
-            })
-        }
+                value: Positioned {
+                    pos: Pos { line: 1, column: 24 },
+                    node: Value::StringType("sport".to_string()),
+                },
+            },
+        }))
     );
     Ok(())
 }
@@ -95,13 +99,61 @@ fn test_parse_query_count() -> Result<()> {
     let ast = parse_moto_file(input)?;
     assert_eq!(ast.len(), 1);
     assert_matches!(&ast[0], Command::Count(query) if
-        query == &Query {
-            condition: Some(Condition {
+        query.filter == Some(Expr::Compare(Positioned {
+            pos: Pos { line: 1, column: 19 },
+            node: Condition {
                 field: "year".to_string(),
                 operator: ">".to_string(),
-                value: Value::Number(2020),
-            })
-        }
+                value: Positioned {
+                    pos: Pos { line: 1, column: 26 },
+                    node: Value::Number(2020),
+                },
+            },
+        }))
+    );
+    Ok(())
+}
+
+// Test for rules: expr, and, or, not, primary (compound WHERE expressions)
+#[test]
+fn test_parse_compound_expr() -> Result<()> {
+    let input = "GET BIKES WHERE year > 2015 AND (type = sport OR NOT type = cruiser)";
+    let ast = parse_moto_file(input)?;
+    assert_eq!(ast.len(), 1);
+    assert_matches!(&ast[0], Command::Get(query) if
+        matches!(&query.filter, Some(Expr::And(lhs, rhs)) if
+            matches!(lhs.as_ref(), Expr::Compare(_))
+                && matches!(rhs.as_ref(), Expr::Or(or_lhs, or_rhs) if
+                    matches!(or_lhs.as_ref(), Expr::Compare(_))
+                        && matches!(or_rhs.as_ref(), Expr::Not(_))
+                )
+        )
+    );
+    Ok(())
+}
+
+// Test for rules: update_stmt, assignment
+#[test]
+fn test_parse_update_stmt() -> Result<()> {
+    let input = "UPDATE BIKES SET year = 2022 WHERE type = sport";
+    let ast = parse_moto_file(input)?;
+    assert_eq!(ast.len(), 1);
+    assert_matches!(&ast[0], Command::Update(update) if
+        update.assignment.node.field == "year"
+            && update.assignment.node.value.node == Value::Number(2022)
+            && matches!(&update.filter, Some(Expr::Compare(_)))
+    );
+    Ok(())
+}
+
+// Test for rule: delete_stmt
+#[test]
+fn test_parse_delete_stmt() -> Result<()> {
+    let input = "DELETE BIKES WHERE year < 2000";
+    let ast = parse_moto_file(input)?;
+    assert_eq!(ast.len(), 1);
+    assert_matches!(&ast[0], Command::Delete(query) if
+        matches!(&query.filter, Some(Expr::Compare(_)))
     );
     Ok(())
 }